@@ -0,0 +1,181 @@
+use std::sync::atomic::{AtomicBool, Ordering};
+
+use argon2::Argon2;
+use base64::engine::general_purpose::STANDARD as BASE64;
+use base64::Engine;
+use chacha20poly1305::aead::{Aead, KeyInit, OsRng};
+use chacha20poly1305::{ChaCha20Poly1305, Key, Nonce};
+use keyring::Entry;
+use rand::RngCore;
+use tauri::{AppHandle, Manager, State};
+
+const LOCK_KEYRING_SERVICE: &str = "world-monitor-lock";
+const WRAPPED_SECRET_KEY: &str = "wrapped-app-secret";
+
+const SALT_LEN: usize = 16;
+const NONCE_LEN: usize = 12;
+
+/// Error string returned by secret commands while the app lock is engaged.
+/// Kept as a plain string (matching this codebase's `Result<T, String>`
+/// convention) so the frontend can match on it directly.
+pub const LOCKED_ERROR: &str = "Locked";
+
+#[derive(Default)]
+pub struct LockState {
+    unlocked: AtomicBool,
+}
+
+fn wrapped_secret_entry() -> Result<Entry, String> {
+    Entry::new(LOCK_KEYRING_SERVICE, WRAPPED_SECRET_KEY)
+        .map_err(|e| format!("Keyring init failed: {e}"))
+}
+
+fn derive_key(passphrase: &str, salt: &[u8]) -> Result<Key, String> {
+    let mut key_bytes = [0u8; 32];
+    Argon2::default()
+        .hash_password_into(passphrase.as_bytes(), salt, &mut key_bytes)
+        .map_err(|e| format!("Failed to derive passphrase key: {e}"))?;
+    Ok(*Key::from_slice(&key_bytes))
+}
+
+/// Whether a passphrase has been set up at all. If not, the app lock is
+/// simply inactive and every secret command passes through unlocked.
+fn is_configured() -> bool {
+    wrapped_secret_entry()
+        .and_then(|e| e.get_password().map_err(|err| err.to_string()))
+        .is_ok()
+}
+
+pub fn require_unlocked(app: &AppHandle) -> Result<(), String> {
+    if is_locked(app.state::<LockState>()) {
+        Err(LOCKED_ERROR.to_string())
+    } else {
+        Ok(())
+    }
+}
+
+pub fn is_unlocked(app: &AppHandle) -> bool {
+    !is_locked(app.state::<LockState>())
+}
+
+#[tauri::command]
+pub fn is_locked(state: State<LockState>) -> bool {
+    is_configured() && !state.unlocked.load(Ordering::SeqCst)
+}
+
+/// Re-engages the app lock and restarts the sidecar so it drops the secrets
+/// it was holding. A no-op if no passphrase has ever been set, since there
+/// is nothing to protect yet.
+#[tauri::command]
+pub fn lock(app: AppHandle) {
+    let state = app.state::<LockState>();
+    if !is_configured() {
+        return;
+    }
+    state.unlocked.store(false, Ordering::SeqCst);
+    crate::local_api::restart_for_profile_change(&app);
+}
+
+/// Unlocks the app. If no passphrase has been configured yet, the given
+/// passphrase becomes the app's passphrase (first-run setup); otherwise it
+/// must match the existing one.
+#[tauri::command]
+pub fn unlock(app: AppHandle, passphrase: String) -> Result<(), String> {
+    let state = app.state::<LockState>();
+    let entry = wrapped_secret_entry()?;
+
+    match entry.get_password() {
+        Err(keyring::Error::NoEntry) => {
+            let salt = random_bytes(SALT_LEN);
+            let nonce_bytes = random_bytes(NONCE_LEN);
+            let key = derive_key(&passphrase, &salt)?;
+            let cipher = ChaCha20Poly1305::new(&key);
+            let mut master_secret = [0u8; 32];
+            OsRng.fill_bytes(&mut master_secret);
+            let nonce = Nonce::from_slice(&nonce_bytes);
+            let ciphertext = cipher
+                .encrypt(nonce, master_secret.as_slice())
+                .map_err(|e| format!("Failed to wrap app secret: {e}"))?;
+
+            let blob = encode_blob(&salt, &nonce_bytes, &ciphertext);
+            entry
+                .set_password(&blob)
+                .map_err(|e| format!("Failed to store app lock: {e}"))?;
+        }
+        Ok(existing_blob) => {
+            let (salt, nonce_bytes, ciphertext) = decode_blob(&existing_blob)?;
+            let key = derive_key(&passphrase, &salt)?;
+            let cipher = ChaCha20Poly1305::new(&key);
+            let nonce = Nonce::from_slice(&nonce_bytes);
+            cipher
+                .decrypt(nonce, ciphertext.as_slice())
+                .map_err(|_| "Incorrect passphrase".to_string())?;
+        }
+        Err(err) => return Err(format!("Failed to read app lock: {err}")),
+    }
+
+    state.unlocked.store(true, Ordering::SeqCst);
+    crate::local_api::restart_for_profile_change(&app);
+    Ok(())
+}
+
+#[tauri::command]
+pub fn change_passphrase(app: AppHandle, old: String, new: String) -> Result<(), String> {
+    let entry = wrapped_secret_entry()?;
+    let existing_blob = entry
+        .get_password()
+        .map_err(|_| "App lock has not been set up yet".to_string())?;
+    let (salt, nonce_bytes, ciphertext) = decode_blob(&existing_blob)?;
+
+    let old_key = derive_key(&old, &salt)?;
+    let cipher = ChaCha20Poly1305::new(&old_key);
+    let nonce = Nonce::from_slice(&nonce_bytes);
+    let master_secret = cipher
+        .decrypt(nonce, ciphertext.as_slice())
+        .map_err(|_| "Incorrect passphrase".to_string())?;
+
+    let new_salt = random_bytes(SALT_LEN);
+    let new_nonce_bytes = random_bytes(NONCE_LEN);
+    let new_key = derive_key(&new, &new_salt)?;
+    let new_cipher = ChaCha20Poly1305::new(&new_key);
+    let new_nonce = Nonce::from_slice(&new_nonce_bytes);
+    let new_ciphertext = new_cipher
+        .encrypt(new_nonce, master_secret.as_slice())
+        .map_err(|e| format!("Failed to wrap app secret: {e}"))?;
+
+    entry
+        .set_password(&encode_blob(&new_salt, &new_nonce_bytes, &new_ciphertext))
+        .map_err(|e| format!("Failed to store app lock: {e}"))?;
+
+    app.state::<LockState>()
+        .unlocked
+        .store(true, Ordering::SeqCst);
+    crate::local_api::restart_for_profile_change(&app);
+    Ok(())
+}
+
+fn random_bytes(len: usize) -> Vec<u8> {
+    let mut buf = vec![0u8; len];
+    OsRng.fill_bytes(&mut buf);
+    buf
+}
+
+fn encode_blob(salt: &[u8], nonce: &[u8], ciphertext: &[u8]) -> String {
+    let mut raw = Vec::with_capacity(salt.len() + nonce.len() + ciphertext.len());
+    raw.extend_from_slice(salt);
+    raw.extend_from_slice(nonce);
+    raw.extend_from_slice(ciphertext);
+    BASE64.encode(raw)
+}
+
+fn decode_blob(blob: &str) -> Result<(Vec<u8>, Vec<u8>, Vec<u8>), String> {
+    let raw = BASE64
+        .decode(blob)
+        .map_err(|e| format!("Corrupt app lock data: {e}"))?;
+    if raw.len() < SALT_LEN + NONCE_LEN {
+        return Err("Corrupt app lock data".to_string());
+    }
+    let (salt, rest) = raw.split_at(SALT_LEN);
+    let (nonce, ciphertext) = rest.split_at(NONCE_LEN);
+    Ok((salt.to_vec(), nonce.to_vec(), ciphertext.to_vec()))
+}
@@ -0,0 +1,167 @@
+use std::io::{BufRead, BufReader, Write};
+use std::thread;
+
+use interprocess::local_socket::{LocalSocketListener, LocalSocketStream};
+use serde::Deserialize;
+use serde_json::json;
+use tauri::AppHandle;
+
+/// Name of the control socket. Resolves to a Unix domain socket under the
+/// OS temp dir on macOS/Linux, and to `\\.\pipe\world-monitor` on Windows.
+pub const SOCKET_NAME: &str = "world-monitor.sock";
+
+#[cfg(unix)]
+fn socket_name() -> String {
+    std::env::temp_dir().join(SOCKET_NAME).display().to_string()
+}
+
+#[cfg(windows)]
+fn socket_name() -> String {
+    "\\\\.\\pipe\\world-monitor".to_string()
+}
+
+#[derive(Deserialize)]
+#[serde(tag = "op", rename_all = "snake_case")]
+enum ControlRequest {
+    GetSecret { key: String },
+    Status,
+    RestartApi,
+}
+
+/// Starts the control socket server on a background thread. The socket
+/// carries secret material, so only one instance of the app (guarded by the
+/// single-instance plugin in `main`) ever owns it, and the Unix socket file
+/// is chmod'd to 0600 once bound.
+pub fn start(app: &AppHandle) {
+    let name = socket_name();
+
+    #[cfg(unix)]
+    {
+        let _ = std::fs::remove_file(&name);
+    }
+
+    let listener = match LocalSocketListener::bind(name.as_str()) {
+        Ok(listener) => listener,
+        Err(err) => {
+            eprintln!("[tauri] failed to bind control socket: {err}");
+            return;
+        }
+    };
+
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        if let Ok(metadata) = std::fs::metadata(&name) {
+            let mut perms = metadata.permissions();
+            perms.set_mode(0o600);
+            let _ = std::fs::set_permissions(&name, perms);
+        }
+    }
+
+    #[cfg(windows)]
+    restrict_windows_pipe_acl(&listener);
+
+    let app = app.clone();
+    thread::spawn(move || {
+        for connection in listener.incoming() {
+            let Ok(stream) = connection else { continue };
+            let app = app.clone();
+            thread::spawn(move || handle_connection(stream, app));
+        }
+    });
+}
+
+/// Restricts the named pipe's DACL to the owning user and SYSTEM, since
+/// `CreateNamedPipe`'s default security descriptor otherwise leaves it
+/// readable by any local process — unacceptable for a socket that hands out
+/// secret material.
+#[cfg(windows)]
+fn restrict_windows_pipe_acl(listener: &LocalSocketListener) {
+    use std::os::windows::io::AsRawHandle;
+
+    use windows_sys::Win32::Security::Authorization::{
+        ConvertStringSecurityDescriptorToSecurityDescriptorW, SDDL_REVISION_1,
+    };
+    use windows_sys::Win32::Security::{SetKernelObjectSecurity, DACL_SECURITY_INFORMATION};
+    use windows_sys::Win32::System::Memory::LocalFree;
+
+    // Owner (creating user) and SYSTEM get full access; everyone else is denied.
+    let sddl: Vec<u16> = "D:P(A;;GA;;;OW)(A;;GA;;;SY)\0".encode_utf16().collect();
+    let mut descriptor: *mut core::ffi::c_void = std::ptr::null_mut();
+
+    let built = unsafe {
+        ConvertStringSecurityDescriptorToSecurityDescriptorW(
+            sddl.as_ptr(),
+            SDDL_REVISION_1 as u32,
+            &mut descriptor,
+            std::ptr::null_mut(),
+        )
+    };
+    if built == 0 || descriptor.is_null() {
+        eprintln!("[tauri] failed to build control socket ACL");
+        return;
+    }
+
+    let handle = listener.as_raw_handle();
+    let applied =
+        unsafe { SetKernelObjectSecurity(handle as _, DACL_SECURITY_INFORMATION, descriptor) };
+    if applied == 0 {
+        eprintln!("[tauri] failed to apply control socket ACL");
+    }
+
+    unsafe {
+        LocalFree(descriptor as isize);
+    }
+}
+
+fn handle_connection(stream: LocalSocketStream, app: AppHandle) {
+    // `LocalSocketStream` isn't `Clone`, so split it into independent
+    // read/write halves instead (the crate hands back owned halves that
+    // each still refer to the same underlying connection).
+    let (read_half, mut writer) = stream.split();
+    let reader = BufReader::new(read_half);
+
+    for line in reader.lines() {
+        let Ok(line) = line else { break };
+        if line.trim().is_empty() {
+            continue;
+        }
+        let response = handle_request(&line, &app);
+        if writeln!(writer, "{response}").is_err() {
+            break;
+        }
+    }
+}
+
+fn handle_request(line: &str, app: &AppHandle) -> String {
+    let request: ControlRequest = match serde_json::from_str(line) {
+        Ok(request) => request,
+        Err(err) => {
+            return json!({"ok": false, "error": format!("invalid request: {err}")}).to_string()
+        }
+    };
+
+    match request {
+        ControlRequest::GetSecret { key } => match crate::applock::require_unlocked(app) {
+            Ok(()) => {
+                let profile = crate::secrets::active_profile_name(app);
+                match crate::secrets::get_secret_for_profile(profile, key) {
+                    Ok(value) => json!({"ok": true, "value": value}).to_string(),
+                    Err(err) => json!({"ok": false, "error": err}).to_string(),
+                }
+            }
+            Err(err) => json!({"ok": false, "error": err}).to_string(),
+        },
+        ControlRequest::Status => {
+            let status = crate::local_api::local_api_status(app.clone());
+            json!({"ok": true, "status": status}).to_string()
+        }
+        ControlRequest::RestartApi => {
+            crate::local_api::stop_local_api(app);
+            match crate::local_api::start_local_api(app) {
+                Ok(_) => json!({"ok": true}).to_string(),
+                Err(err) => json!({"ok": false, "error": err}).to_string(),
+            }
+        }
+    }
+}
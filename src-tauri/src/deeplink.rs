@@ -0,0 +1,105 @@
+use tauri::{AppHandle, Emitter};
+use url::Url;
+
+pub const SCHEME: &str = "worldmonitor";
+
+#[derive(Clone, serde::Serialize)]
+pub struct PendingImport {
+    pub entries: Vec<(String, String)>,
+    pub rejected: Vec<String>,
+}
+
+/// Registers the `worldmonitor://` scheme and wires up the handler that
+/// parses incoming links like `worldmonitor://config?FRED_API_KEY=...` into
+/// a confirmation prompt for the frontend. On Linux this also needs a
+/// `.desktop` MIME association, since there's no OS-level scheme registry
+/// to hook into at runtime the way macOS/Windows have.
+pub fn register(app: &AppHandle) {
+    #[cfg(target_os = "linux")]
+    register_linux_desktop_entry();
+
+    let app = app.clone();
+    let _ = tauri_plugin_deep_link::register(SCHEME, move |request| {
+        handle_url(&app, &request);
+    });
+}
+
+fn handle_url(app: &AppHandle, raw_url: &str) {
+    let Ok(url) = Url::parse(raw_url) else {
+        eprintln!("[tauri] ignoring malformed deep link: {raw_url}");
+        return;
+    };
+
+    if url.scheme() != SCHEME || url.host_str() != Some("config") {
+        eprintln!("[tauri] ignoring unsupported deep link: {raw_url}");
+        return;
+    }
+
+    let mut entries = Vec::new();
+    let mut rejected = Vec::new();
+    for (key, value) in url.query_pairs() {
+        let key = key.into_owned();
+        if crate::secrets::SUPPORTED_SECRET_KEYS.contains(&key.as_str()) {
+            entries.push((key, value.into_owned()));
+        } else {
+            rejected.push(key);
+        }
+    }
+
+    if entries.is_empty() {
+        return;
+    }
+
+    // Writing secrets happens only after the user confirms in the UI;
+    // `apply_deep_link_import` is what actually writes them, via
+    // `set_secret_for_profile` on the active profile.
+    let _ = app.emit(
+        "deep-link://import-request",
+        PendingImport { entries, rejected },
+    );
+}
+
+#[cfg(target_os = "linux")]
+fn register_linux_desktop_entry() {
+    let Some(home) = dirs::home_dir() else { return };
+    let apps_dir = home.join(".local/share/applications");
+    if std::fs::create_dir_all(&apps_dir).is_err() {
+        return;
+    }
+
+    let Ok(exe) = std::env::current_exe() else {
+        return;
+    };
+    let desktop_entry = format!(
+        "[Desktop Entry]\n\
+         Type=Application\n\
+         Name=World Monitor\n\
+         Exec={} %u\n\
+         NoDisplay=true\n\
+         MimeType=x-scheme-handler/{SCHEME};\n",
+        exe.display()
+    );
+
+    let _ = std::fs::write(
+        apps_dir.join("world-monitor-deeplink.desktop"),
+        desktop_entry,
+    );
+    let _ = std::process::Command::new("update-desktop-database")
+        .arg(apps_dir)
+        .status();
+}
+
+/// Called by the frontend once the user has reviewed a `PendingImport` and
+/// confirmed it should be written to the keyring.
+#[tauri::command]
+pub fn apply_deep_link_import(
+    app: AppHandle,
+    entries: Vec<(String, String)>,
+) -> Result<(), String> {
+    crate::applock::require_unlocked(&app)?;
+    let profile = crate::secrets::active_profile_name(&app);
+    for (key, value) in entries {
+        crate::secrets::set_secret_for_profile(profile.clone(), key, value)?;
+    }
+    Ok(())
+}
@@ -0,0 +1,318 @@
+use std::io::{Read, Write};
+use std::net::{TcpListener, TcpStream};
+use std::path::PathBuf;
+use std::process::{Child, Command, Stdio};
+use std::sync::atomic::{AtomicU16, AtomicU64, Ordering};
+use std::sync::Mutex;
+use std::thread;
+use std::time::{Duration, Instant};
+
+use tauri::{AppHandle, Emitter, Manager};
+
+pub const PREFERRED_LOCAL_API_PORT: u16 = 46123;
+const READINESS_TIMEOUT: Duration = Duration::from_secs(15);
+
+const INITIAL_BACKOFF: Duration = Duration::from_millis(500);
+const MAX_BACKOFF: Duration = Duration::from_secs(30);
+const HEALTHY_RESET_AFTER: Duration = Duration::from_secs(60);
+
+#[derive(Default)]
+pub struct LocalApiState {
+    pub(crate) child: Mutex<Option<Child>>,
+    pub(crate) restarts: Mutex<u32>,
+    pub(crate) last_error: Mutex<Option<String>>,
+    /// Bumped every time the sidecar is stopped or (re)started. A supervisor
+    /// thread compares its captured generation against the current value to
+    /// know it's been superseded, instead of racing on a shared bool that
+    /// both an old and a new supervisor could read as "keep going".
+    pub(crate) generation: AtomicU64,
+    pub(crate) port: AtomicU16,
+}
+
+#[tauri::command]
+pub fn local_api_port(app: AppHandle) -> u16 {
+    app.state::<LocalApiState>().port.load(Ordering::SeqCst)
+}
+
+/// Binds the preferred port if it's free, otherwise grabs any free
+/// ephemeral port, so a second running copy (or another service already on
+/// 46123) doesn't stop the sidecar from starting.
+fn allocate_port() -> Result<u16, String> {
+    if let Ok(listener) = TcpListener::bind(("127.0.0.1", PREFERRED_LOCAL_API_PORT)) {
+        let port = listener
+            .local_addr()
+            .map(|a| a.port())
+            .unwrap_or(PREFERRED_LOCAL_API_PORT);
+        drop(listener);
+        return Ok(port);
+    }
+
+    let listener = TcpListener::bind(("127.0.0.1", 0))
+        .map_err(|e| format!("Failed to allocate a local API port: {e}"))?;
+    let port = listener
+        .local_addr()
+        .map_err(|e| format!("Failed to read allocated port: {e}"))?
+        .port();
+    drop(listener);
+    Ok(port)
+}
+
+#[derive(Clone, serde::Serialize)]
+pub struct LocalApiStatus {
+    pub running: bool,
+    pub pid: Option<u32>,
+    pub port: u16,
+    pub restarts: u32,
+    pub last_error: Option<String>,
+}
+
+#[tauri::command]
+pub fn local_api_status(app: AppHandle) -> LocalApiStatus {
+    let state = app.state::<LocalApiState>();
+    let pid = state
+        .child
+        .lock()
+        .ok()
+        .and_then(|slot| slot.as_ref().map(|c| c.id()));
+    LocalApiStatus {
+        running: pid.is_some(),
+        pid,
+        port: state.port.load(Ordering::SeqCst),
+        restarts: state.restarts.lock().map(|r| *r).unwrap_or_default(),
+        last_error: state.last_error.lock().ok().and_then(|e| e.clone()),
+    }
+}
+
+fn emit_status(app: &AppHandle) {
+    let status = local_api_status(app.clone());
+    let _ = app.emit("local-api://status", status);
+}
+
+fn local_api_paths(app: &AppHandle) -> (PathBuf, PathBuf) {
+    let resource_dir = app
+        .path()
+        .resource_dir()
+        .unwrap_or_else(|_| PathBuf::from("."));
+
+    let sidecar_script = if cfg!(debug_assertions) {
+        PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("sidecar/local-api-server.mjs")
+    } else {
+        resource_dir.join("sidecar/local-api-server.mjs")
+    };
+
+    let api_dir_root = if cfg!(debug_assertions) {
+        PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+            .parent()
+            .map(PathBuf::from)
+            .unwrap_or_else(|| PathBuf::from("."))
+    } else {
+        resource_dir
+    };
+
+    (sidecar_script, api_dir_root)
+}
+
+fn spawn_child(app: &AppHandle, port: u16) -> Result<Child, String> {
+    let (script, resource_root) = local_api_paths(app);
+    if !script.exists() {
+        return Err(format!(
+            "Local API sidecar script missing at {}",
+            script.display()
+        ));
+    }
+
+    let node = crate::node_runtime::resolve_node_binary(app).ok_or_else(|| {
+        "Could not find a Node.js runtime on this machine. Install Node.js and try again."
+            .to_string()
+    })?;
+
+    let mut cmd = Command::new(node);
+    cmd.arg(&script)
+        .env("LOCAL_API_PORT", port.to_string())
+        .env("LOCAL_API_RESOURCE_DIR", resource_root)
+        .env("LOCAL_API_MODE", "tauri-sidecar")
+        .stdout(Stdio::null())
+        .stderr(Stdio::inherit());
+
+    // While the app lock is engaged the sidecar is started with an empty
+    // secret set rather than not at all, so health checks still work.
+    if crate::applock::is_unlocked(app) {
+        let profile = crate::secrets::active_profile_name(app);
+        for (key, value) in crate::secrets::profile_env_vars(&profile) {
+            cmd.env(key, value);
+        }
+    }
+
+    cmd.spawn()
+        .map_err(|e| format!("Failed to launch local API: {e}"))
+}
+
+fn poll_healthz(port: u16, timeout: Duration) -> bool {
+    let deadline = Instant::now() + timeout;
+    while Instant::now() < deadline {
+        if http_get_ok(port, "/healthz") {
+            return true;
+        }
+        thread::sleep(Duration::from_millis(150));
+    }
+    false
+}
+
+fn http_get_ok(port: u16, path: &str) -> bool {
+    let Ok(mut stream) = TcpStream::connect(format!("127.0.0.1:{port}")) else {
+        return false;
+    };
+    let _ = stream.set_read_timeout(Some(Duration::from_millis(500)));
+    let request =
+        format!("GET {path} HTTP/1.1\r\nHost: 127.0.0.1:{port}\r\nConnection: close\r\n\r\n");
+    if stream.write_all(request.as_bytes()).is_err() {
+        return false;
+    }
+    let mut buf = [0u8; 32];
+    match stream.read(&mut buf) {
+        Ok(n) if n > 0 => {
+            let response = String::from_utf8_lossy(&buf[..n]);
+            response.starts_with("HTTP/1.1 200") || response.starts_with("HTTP/1.0 200")
+        }
+        _ => false,
+    }
+}
+
+/// Spawns the sidecar and a supervisor thread that restarts it with
+/// exponential backoff whenever it exits unexpectedly, then blocks until
+/// `/healthz` responds on the allocated port (or `READINESS_TIMEOUT`
+/// elapses) so `setup` doesn't return before the webview can reach it.
+pub fn start_local_api(app: &AppHandle) -> Result<(), String> {
+    let state = app.state::<LocalApiState>();
+    let port;
+    {
+        let mut slot = state
+            .child
+            .lock()
+            .map_err(|_| "Failed to lock local API state".to_string())?;
+        if slot.is_some() {
+            return Ok(());
+        }
+        port = allocate_port()?;
+        let child = spawn_child(app, port)?;
+        *slot = Some(child);
+    }
+    state.port.store(port, Ordering::SeqCst);
+    emit_status(app);
+
+    let generation = state.generation.fetch_add(1, Ordering::SeqCst) + 1;
+    let supervisor_app = app.clone();
+    thread::spawn(move || supervisor_loop(supervisor_app, generation));
+
+    if !poll_healthz(port, READINESS_TIMEOUT) {
+        if let Ok(mut last_error) = state.last_error.lock() {
+            *last_error = Some("local API did not report healthy in time".to_string());
+        }
+    }
+    Ok(())
+}
+
+fn supervisor_loop(app: AppHandle, generation: u64) {
+    let state = app.state::<LocalApiState>();
+    let mut backoff = INITIAL_BACKOFF;
+    let mut healthy_since = Instant::now();
+
+    loop {
+        if state.generation.load(Ordering::SeqCst) != generation {
+            return;
+        }
+        thread::sleep(Duration::from_millis(300));
+
+        let exited = {
+            let mut slot = match state.child.lock() {
+                Ok(slot) => slot,
+                Err(_) => return,
+            };
+            match slot.as_mut() {
+                Some(child) => match child.try_wait() {
+                    Ok(Some(status)) => {
+                        *slot = None;
+                        Some(status.code())
+                    }
+                    Ok(None) => None,
+                    Err(_) => None,
+                },
+                None => None,
+            }
+        };
+
+        if state.generation.load(Ordering::SeqCst) != generation {
+            return;
+        }
+
+        if let Some(code) = exited {
+            if let Ok(mut last_error) = state.last_error.lock() {
+                *last_error = Some(format!(
+                    "local API sidecar exited unexpectedly (code {code:?})"
+                ));
+            }
+            emit_status(&app);
+
+            thread::sleep(backoff);
+            backoff = (backoff * 2).min(MAX_BACKOFF);
+
+            let port = state.port.load(Ordering::SeqCst);
+            match spawn_child(&app, port) {
+                Ok(mut child) => {
+                    // `stop_local_api`/`restart_for_profile_change` may have run
+                    // while we were sleeping off the backoff above. Re-check the
+                    // generation right before claiming the slot so a superseded
+                    // supervisor doesn't overwrite whatever the newer one put
+                    // there (and orphan the process it just spawned, since
+                    // `Child::drop` doesn't kill it).
+                    let mut slot = match state.child.lock() {
+                        Ok(slot) => slot,
+                        Err(_) => return,
+                    };
+                    if state.generation.load(Ordering::SeqCst) != generation {
+                        let _ = child.kill();
+                        return;
+                    }
+                    *slot = Some(child);
+                    drop(slot);
+                    if let Ok(mut restarts) = state.restarts.lock() {
+                        *restarts += 1;
+                    }
+                    healthy_since = Instant::now();
+                    emit_status(&app);
+                }
+                Err(err) => {
+                    if let Ok(mut last_error) = state.last_error.lock() {
+                        *last_error = Some(err);
+                    }
+                    emit_status(&app);
+                }
+            }
+            continue;
+        }
+
+        if healthy_since.elapsed() >= HEALTHY_RESET_AFTER {
+            backoff = INITIAL_BACKOFF;
+        }
+    }
+}
+
+pub fn stop_local_api(app: &AppHandle) {
+    if let Some(state) = app.try_state::<LocalApiState>() {
+        state.generation.fetch_add(1, Ordering::SeqCst);
+        if let Ok(mut slot) = state.child.lock() {
+            if let Some(mut child) = slot.take() {
+                let _ = child.kill();
+            }
+        }
+    }
+}
+
+/// Restarts the sidecar so it picks up the newly active profile's secrets.
+/// Used after `set_active_profile` switches which keyring namespace is live.
+pub fn restart_for_profile_change(app: &AppHandle) {
+    stop_local_api(app);
+    if let Err(err) = start_local_api(app) {
+        eprintln!("[tauri] failed to restart local API after profile change: {err}");
+    }
+}
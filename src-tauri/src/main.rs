@@ -1,155 +1,61 @@
 #![cfg_attr(not(debug_assertions), windows_subsystem = "windows")]
 
-use std::path::PathBuf;
-use std::process::{Child, Command, Stdio};
-use std::sync::Mutex;
-
-use keyring::Entry;
-use tauri::{AppHandle, Manager, RunEvent};
-
-const LOCAL_API_PORT: &str = "46123";
-const KEYRING_SERVICE: &str = "world-monitor";
-const SUPPORTED_SECRET_KEYS: [&str; 13] = [
-    "GROQ_API_KEY",
-    "OPENROUTER_API_KEY",
-    "FRED_API_KEY",
-    "EIA_API_KEY",
-    "CLOUDFLARE_API_TOKEN",
-    "ACLED_ACCESS_TOKEN",
-    "WINGBITS_API_KEY",
-    "WS_RELAY_URL",
-    "VITE_OPENSKY_RELAY_URL",
-    "OPENSKY_CLIENT_ID",
-    "OPENSKY_CLIENT_SECRET",
-    "AISSTREAM_API_KEY",
-    "VITE_WS_RELAY_URL",
-];
-
-#[derive(Default)]
-struct LocalApiState {
-    child: Mutex<Option<Child>>,
-}
-
-fn secret_entry(key: &str) -> Result<Entry, String> {
-    if !SUPPORTED_SECRET_KEYS.contains(&key) {
-        return Err(format!("Unsupported secret key: {key}"));
-    }
-    Entry::new(KEYRING_SERVICE, key).map_err(|e| format!("Keyring init failed: {e}"))
-}
-
-#[tauri::command]
-fn list_supported_secret_keys() -> Vec<String> {
-    SUPPORTED_SECRET_KEYS.iter().map(|key| (*key).to_string()).collect()
-}
-
-#[tauri::command]
-fn get_secret(key: String) -> Result<Option<String>, String> {
-    let entry = secret_entry(&key)?;
-    match entry.get_password() {
-        Ok(value) => Ok(Some(value)),
-        Err(keyring::Error::NoEntry) => Ok(None),
-        Err(err) => Err(format!("Failed to read keyring secret: {err}")),
-    }
-}
-
-#[tauri::command]
-fn set_secret(key: String, value: String) -> Result<(), String> {
-    let entry = secret_entry(&key)?;
-    entry
-        .set_password(&value)
-        .map_err(|e| format!("Failed to write keyring secret: {e}"))
-}
-
-#[tauri::command]
-fn delete_secret(key: String) -> Result<(), String> {
-    let entry = secret_entry(&key)?;
-    match entry.delete_credential() {
-        Ok(_) => Ok(()),
-        Err(keyring::Error::NoEntry) => Ok(()),
-        Err(err) => Err(format!("Failed to delete keyring secret: {err}")),
-    }
-}
-
-fn local_api_paths(app: &AppHandle) -> (PathBuf, PathBuf) {
-    let resource_dir = app
-        .path()
-        .resource_dir()
-        .unwrap_or_else(|_| PathBuf::from("."));
-
-    let sidecar_script = if cfg!(debug_assertions) {
-        PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("sidecar/local-api-server.mjs")
-    } else {
-        resource_dir.join("sidecar/local-api-server.mjs")
-    };
-
-    let api_dir_root = if cfg!(debug_assertions) {
-        PathBuf::from(env!("CARGO_MANIFEST_DIR"))
-            .parent()
-            .map(PathBuf::from)
-            .unwrap_or_else(|| PathBuf::from("."))
-    } else {
-        resource_dir
-    };
-
-    (sidecar_script, api_dir_root)
-}
-
-fn start_local_api(app: &AppHandle) -> Result<(), String> {
-    let state = app.state::<LocalApiState>();
-    let mut slot = state
-        .child
-        .lock()
-        .map_err(|_| "Failed to lock local API state".to_string())?;
-    if slot.is_some() {
-        return Ok(());
-    }
-
-    let (script, resource_root) = local_api_paths(app);
-    if !script.exists() {
-        return Err(format!(
-            "Local API sidecar script missing at {}",
-            script.display()
-        ));
-    }
-
-    let mut cmd = Command::new("node");
-    cmd.arg(&script)
-        .env("LOCAL_API_PORT", LOCAL_API_PORT)
-        .env("LOCAL_API_RESOURCE_DIR", resource_root)
-        .env("LOCAL_API_MODE", "tauri-sidecar")
-        .stdout(Stdio::null())
-        .stderr(Stdio::inherit());
-
-    let child = cmd
-        .spawn()
-        .map_err(|e| format!("Failed to launch local API: {e}"))?;
-    *slot = Some(child);
-    Ok(())
-}
-
-fn stop_local_api(app: &AppHandle) {
-    if let Ok(state) = app.try_state::<LocalApiState>().ok_or(()) {
-        if let Ok(mut slot) = state.child.lock() {
-            if let Some(mut child) = slot.take() {
-                let _ = child.kill();
-            }
-        }
-    }
-}
+use tauri::RunEvent;
+
+mod applock;
+mod control_socket;
+mod deeplink;
+mod local_api;
+mod node_runtime;
+mod secrets;
+
+use applock::{change_passphrase, is_locked, lock, unlock, LockState};
+use deeplink::apply_deep_link_import;
+use local_api::{local_api_port, local_api_status, start_local_api, stop_local_api, LocalApiState};
+use node_runtime::check_runtime;
+use secrets::{
+    active_profile, create_profile, delete_profile, delete_secret, delete_secret_for_profile,
+    get_secret, get_secret_for_profile, list_profiles, list_stored_secrets,
+    list_supported_secret_keys, set_active_profile, set_secret, set_secret_for_profile,
+    ProfileState,
+};
 
 fn main() {
     tauri::Builder::default()
+        .plugin(tauri_plugin_single_instance::init(|_app, _argv, _cwd| {}))
         .manage(LocalApiState::default())
+        .manage(ProfileState::new())
+        .manage(LockState::default())
         .invoke_handler(tauri::generate_handler![
             list_supported_secret_keys,
             get_secret,
             set_secret,
-            delete_secret
+            delete_secret,
+            get_secret_for_profile,
+            set_secret_for_profile,
+            delete_secret_for_profile,
+            list_stored_secrets,
+            list_profiles,
+            create_profile,
+            delete_profile,
+            active_profile,
+            set_active_profile,
+            local_api_status,
+            local_api_port,
+            check_runtime,
+            apply_deep_link_import,
+            is_locked,
+            lock,
+            unlock,
+            change_passphrase
         ])
         .setup(|app| {
-            if let Err(err) = start_local_api(&app.handle()) {
+            let handle = app.handle();
+            if let Err(err) = start_local_api(&handle) {
                 eprintln!("[tauri] local API sidecar failed to start: {err}");
             }
+            control_socket::start(&handle);
+            deeplink::register(&handle);
             Ok(())
         })
         .build(tauri::generate_context!())
@@ -0,0 +1,101 @@
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+use tauri::{AppHandle, Manager};
+
+/// Well-known per-OS install locations checked when `node` isn't resolvable
+/// on `PATH` (the common case for GUI-launched Tauri apps, whose PATH often
+/// differs from a terminal shell's).
+#[cfg(target_os = "macos")]
+const FALLBACK_PATHS: &[&str] = &[
+    "/usr/local/bin/node",
+    "/opt/homebrew/bin/node",
+    "/usr/bin/node",
+];
+
+#[cfg(target_os = "linux")]
+const FALLBACK_PATHS: &[&str] = &["/usr/local/bin/node", "/usr/bin/node", "/snap/bin/node"];
+
+#[cfg(target_os = "windows")]
+const FALLBACK_PATHS: &[&str] = &[
+    "C:\\Program Files\\nodejs\\node.exe",
+    "C:\\Program Files (x86)\\nodejs\\node.exe",
+];
+
+fn bundled_node_path(app: &AppHandle) -> Option<PathBuf> {
+    let resource_dir = app.path().resource_dir().ok()?;
+    let bundled = if cfg!(target_os = "windows") {
+        resource_dir.join("sidecar/node.exe")
+    } else {
+        resource_dir.join("sidecar/node")
+    };
+    bundled.exists().then_some(bundled)
+}
+
+/// Checks that `path` exists and, on Unix, that it actually carries an exec
+/// bit — a fallback candidate that merely exists but isn't runnable should
+/// be skipped here rather than failing opaquely once we try to `spawn` it.
+#[cfg(unix)]
+fn is_executable(path: &Path) -> bool {
+    use std::os::unix::fs::PermissionsExt;
+    std::fs::metadata(path)
+        .map(|metadata| metadata.permissions().mode() & 0o111 != 0)
+        .unwrap_or(false)
+}
+
+#[cfg(not(unix))]
+fn is_executable(path: &Path) -> bool {
+    path.exists()
+}
+
+/// Resolves a usable `node` binary by searching `PATH` (via the `which`
+/// crate), falling back to known per-OS install locations, and finally to a
+/// `node` binary bundled as a Tauri resource next to `local-api-server.mjs`.
+pub fn resolve_node_binary(app: &AppHandle) -> Option<PathBuf> {
+    if let Ok(path) = which::which("node") {
+        return Some(path);
+    }
+
+    for candidate in FALLBACK_PATHS {
+        let path = PathBuf::from(candidate);
+        if is_executable(&path) {
+            return Some(path);
+        }
+    }
+
+    bundled_node_path(app)
+}
+
+#[derive(Clone, serde::Serialize)]
+pub struct RuntimeCheck {
+    pub found: bool,
+    pub path: Option<String>,
+    pub version: Option<String>,
+}
+
+/// Reports the resolved node binary and its `--version` output so the
+/// frontend can point users at an install step instead of surfacing the
+/// opaque "Failed to launch local API" error.
+#[tauri::command]
+pub fn check_runtime(app: AppHandle) -> RuntimeCheck {
+    let Some(path) = resolve_node_binary(&app) else {
+        return RuntimeCheck {
+            found: false,
+            path: None,
+            version: None,
+        };
+    };
+
+    let version = Command::new(&path)
+        .arg("--version")
+        .output()
+        .ok()
+        .filter(|out| out.status.success())
+        .map(|out| String::from_utf8_lossy(&out.stdout).trim().to_string());
+
+    RuntimeCheck {
+        found: true,
+        path: Some(path.display().to_string()),
+        version,
+    }
+}
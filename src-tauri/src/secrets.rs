@@ -0,0 +1,278 @@
+use std::path::PathBuf;
+use std::sync::Mutex;
+
+use keyring::Entry;
+use tauri::{AppHandle, Manager, State};
+
+const KEYRING_SERVICE_PREFIX: &str = "world-monitor";
+/// Service name used before profiles existed. Reads against the `default`
+/// profile fall back to this and migrate the value forward, so upgrading
+/// users don't see their existing keys reported as missing.
+const LEGACY_KEYRING_SERVICE: &str = "world-monitor";
+pub const DEFAULT_PROFILE: &str = "default";
+
+pub const SUPPORTED_SECRET_KEYS: [&str; 13] = [
+    "GROQ_API_KEY",
+    "OPENROUTER_API_KEY",
+    "FRED_API_KEY",
+    "EIA_API_KEY",
+    "CLOUDFLARE_API_TOKEN",
+    "ACLED_ACCESS_TOKEN",
+    "WINGBITS_API_KEY",
+    "WS_RELAY_URL",
+    "VITE_OPENSKY_RELAY_URL",
+    "OPENSKY_CLIENT_ID",
+    "OPENSKY_CLIENT_SECRET",
+    "AISSTREAM_API_KEY",
+    "VITE_WS_RELAY_URL",
+];
+
+/// Tracks which profile the running sidecar and keyring commands should act
+/// on. Profiles themselves are just a namespace prefix over keyring entries;
+/// the list of known profile names lives in `profiles.json` since OS
+/// keychains don't support enumerating services.
+#[derive(Default)]
+pub struct ProfileState {
+    pub(crate) active: Mutex<String>,
+}
+
+impl ProfileState {
+    pub fn new() -> Self {
+        Self {
+            active: Mutex::new(DEFAULT_PROFILE.to_string()),
+        }
+    }
+}
+
+fn keyring_service(profile: &str) -> String {
+    format!("{KEYRING_SERVICE_PREFIX}:{profile}")
+}
+
+fn secret_entry(profile: &str, key: &str) -> Result<Entry, String> {
+    if !SUPPORTED_SECRET_KEYS.contains(&key) {
+        return Err(format!("Unsupported secret key: {key}"));
+    }
+    Entry::new(&keyring_service(profile), key).map_err(|e| format!("Keyring init failed: {e}"))
+}
+
+fn legacy_entry(key: &str) -> Result<Entry, String> {
+    if !SUPPORTED_SECRET_KEYS.contains(&key) {
+        return Err(format!("Unsupported secret key: {key}"));
+    }
+    Entry::new(LEGACY_KEYRING_SERVICE, key).map_err(|e| format!("Keyring init failed: {e}"))
+}
+
+/// If `profile` is `default` and `key` has no namespaced entry yet, checks
+/// the pre-profile flat `"world-monitor"` service and copies the value
+/// forward so it's treated as configured from then on.
+fn migrate_legacy_secret(profile: &str, key: &str) -> Option<String> {
+    if profile != DEFAULT_PROFILE {
+        return None;
+    }
+    let value = legacy_entry(key).ok()?.get_password().ok()?;
+    if let Ok(entry) = secret_entry(profile, key) {
+        let _ = entry.set_password(&value);
+    }
+    Some(value)
+}
+
+fn read_secret(profile: &str, key: &str) -> Result<Option<String>, String> {
+    let entry = secret_entry(profile, key)?;
+    match entry.get_password() {
+        Ok(value) => Ok(Some(value)),
+        Err(keyring::Error::NoEntry) => Ok(migrate_legacy_secret(profile, key)),
+        Err(err) => Err(format!("Failed to read keyring secret: {err}")),
+    }
+}
+
+fn profiles_file(app: &AppHandle) -> Result<PathBuf, String> {
+    let dir = app
+        .path()
+        .app_config_dir()
+        .map_err(|e| format!("Failed to resolve app config dir: {e}"))?;
+    std::fs::create_dir_all(&dir).map_err(|e| format!("Failed to create app config dir: {e}"))?;
+    Ok(dir.join("profiles.json"))
+}
+
+fn read_profiles(app: &AppHandle) -> Result<Vec<String>, String> {
+    let path = profiles_file(app)?;
+    if !path.exists() {
+        return Ok(vec![DEFAULT_PROFILE.to_string()]);
+    }
+    let raw =
+        std::fs::read_to_string(&path).map_err(|e| format!("Failed to read profiles: {e}"))?;
+    let mut profiles: Vec<String> = serde_json::from_str(&raw).unwrap_or_default();
+    if !profiles.iter().any(|p| p == DEFAULT_PROFILE) {
+        profiles.insert(0, DEFAULT_PROFILE.to_string());
+    }
+    Ok(profiles)
+}
+
+fn write_profiles(app: &AppHandle, profiles: &[String]) -> Result<(), String> {
+    let path = profiles_file(app)?;
+    let raw = serde_json::to_string_pretty(profiles)
+        .map_err(|e| format!("Failed to serialize profiles: {e}"))?;
+    std::fs::write(&path, raw).map_err(|e| format!("Failed to write profiles: {e}"))
+}
+
+/// Reads every configured secret for `profile` into `(KEY, value)` pairs,
+/// suitable for handing to the sidecar's environment.
+pub fn profile_env_vars(profile: &str) -> Vec<(String, String)> {
+    SUPPORTED_SECRET_KEYS
+        .iter()
+        .filter_map(|key| {
+            read_secret(profile, key)
+                .ok()
+                .flatten()
+                .map(|value| ((*key).to_string(), value))
+        })
+        .collect()
+}
+
+pub fn active_profile_name(app: &AppHandle) -> String {
+    app.state::<ProfileState>()
+        .active
+        .lock()
+        .map(|p| p.clone())
+        .unwrap_or_else(|_| DEFAULT_PROFILE.to_string())
+}
+
+#[tauri::command]
+pub fn list_profiles(app: AppHandle) -> Result<Vec<String>, String> {
+    read_profiles(&app)
+}
+
+#[tauri::command]
+pub fn create_profile(app: AppHandle, name: String) -> Result<(), String> {
+    if name.trim().is_empty() {
+        return Err("Profile name cannot be empty".to_string());
+    }
+    let mut profiles = read_profiles(&app)?;
+    if profiles.iter().any(|p| p == &name) {
+        return Err(format!("Profile '{name}' already exists"));
+    }
+    profiles.push(name);
+    write_profiles(&app, &profiles)
+}
+
+#[tauri::command]
+pub fn delete_profile(app: AppHandle, name: String) -> Result<(), String> {
+    if name == DEFAULT_PROFILE {
+        return Err("The default profile cannot be deleted".to_string());
+    }
+    let mut profiles = read_profiles(&app)?;
+    profiles.retain(|p| p != &name);
+    write_profiles(&app, &profiles)?;
+
+    for key in SUPPORTED_SECRET_KEYS {
+        if let Ok(entry) = secret_entry(&name, key) {
+            let _ = entry.delete_credential();
+        }
+    }
+
+    let state = app.state::<ProfileState>();
+    if let Ok(mut active) = state.active.lock() {
+        if *active == name {
+            *active = DEFAULT_PROFILE.to_string();
+        }
+    }
+    Ok(())
+}
+
+#[tauri::command]
+pub fn active_profile(state: State<ProfileState>) -> String {
+    state
+        .active
+        .lock()
+        .map(|p| p.clone())
+        .unwrap_or_else(|_| DEFAULT_PROFILE.to_string())
+}
+
+#[tauri::command]
+pub fn set_active_profile(app: AppHandle, name: String) -> Result<(), String> {
+    let profiles = read_profiles(&app)?;
+    if !profiles.iter().any(|p| p == &name) {
+        return Err(format!("Unknown profile '{name}'"));
+    }
+    {
+        let state = app.state::<ProfileState>();
+        let mut active = state
+            .active
+            .lock()
+            .map_err(|_| "Failed to lock profile state".to_string())?;
+        *active = name;
+    }
+    crate::local_api::restart_for_profile_change(&app);
+    Ok(())
+}
+
+#[tauri::command]
+pub fn list_supported_secret_keys() -> Vec<String> {
+    SUPPORTED_SECRET_KEYS
+        .iter()
+        .map(|key| (*key).to_string())
+        .collect()
+}
+
+#[derive(Clone, serde::Serialize)]
+pub struct StoredSecretStatus {
+    pub key: String,
+    pub configured: bool,
+}
+
+#[tauri::command]
+pub fn list_stored_secrets(profile: String) -> Vec<StoredSecretStatus> {
+    SUPPORTED_SECRET_KEYS
+        .iter()
+        .map(|key| {
+            let configured = read_secret(&profile, key)
+                .map(|value| value.is_some())
+                .unwrap_or(false);
+            StoredSecretStatus {
+                key: (*key).to_string(),
+                configured,
+            }
+        })
+        .collect()
+}
+
+#[tauri::command]
+pub fn get_secret(app: AppHandle, key: String) -> Result<Option<String>, String> {
+    crate::applock::require_unlocked(&app)?;
+    get_secret_for_profile(active_profile_name(&app), key)
+}
+
+#[tauri::command]
+pub fn set_secret(app: AppHandle, key: String, value: String) -> Result<(), String> {
+    crate::applock::require_unlocked(&app)?;
+    set_secret_for_profile(active_profile_name(&app), key, value)
+}
+
+#[tauri::command]
+pub fn delete_secret(app: AppHandle, key: String) -> Result<(), String> {
+    crate::applock::require_unlocked(&app)?;
+    delete_secret_for_profile(active_profile_name(&app), key)
+}
+
+#[tauri::command]
+pub fn get_secret_for_profile(profile: String, key: String) -> Result<Option<String>, String> {
+    read_secret(&profile, &key)
+}
+
+#[tauri::command]
+pub fn set_secret_for_profile(profile: String, key: String, value: String) -> Result<(), String> {
+    let entry = secret_entry(&profile, &key)?;
+    entry
+        .set_password(&value)
+        .map_err(|e| format!("Failed to write keyring secret: {e}"))
+}
+
+#[tauri::command]
+pub fn delete_secret_for_profile(profile: String, key: String) -> Result<(), String> {
+    let entry = secret_entry(&profile, &key)?;
+    match entry.delete_credential() {
+        Ok(_) => Ok(()),
+        Err(keyring::Error::NoEntry) => Ok(()),
+        Err(err) => Err(format!("Failed to delete keyring secret: {err}")),
+    }
+}
@@ -0,0 +1,68 @@
+use std::env;
+use std::io::{BufRead, BufReader, Write};
+
+use interprocess::local_socket::LocalSocketStream;
+
+#[cfg(unix)]
+fn socket_name() -> String {
+    std::env::temp_dir()
+        .join("world-monitor.sock")
+        .display()
+        .to_string()
+}
+
+#[cfg(windows)]
+fn socket_name() -> String {
+    "\\\\.\\pipe\\world-monitor".to_string()
+}
+
+fn usage() -> ! {
+    eprintln!(
+        "Usage: world-monitor-cli <status|restart-api|get-secret KEY>\n\n\
+         Talks to a running World Monitor app over its local control socket."
+    );
+    std::process::exit(2);
+}
+
+fn main() {
+    let mut args = env::args().skip(1);
+    let request = match args.next().as_deref() {
+        Some("status") => r#"{"op":"status"}"#.to_string(),
+        Some("restart-api") => r#"{"op":"restart_api"}"#.to_string(),
+        Some("get-secret") => {
+            let Some(key) = args.next() else {
+                eprintln!("get-secret requires a KEY argument");
+                usage();
+            };
+            format!(r#"{{"op":"get_secret","key":{key:?}}}"#)
+        }
+        _ => usage(),
+    };
+
+    let mut stream = match LocalSocketStream::connect(socket_name().as_str()) {
+        Ok(stream) => stream,
+        Err(err) => {
+            eprintln!("Could not reach World Monitor (is it running?): {err}");
+            std::process::exit(1);
+        }
+    };
+
+    if let Err(err) = writeln!(stream, "{request}") {
+        eprintln!("Failed to write request: {err}");
+        std::process::exit(1);
+    }
+
+    let mut reader = BufReader::new(stream);
+    let mut line = String::new();
+    match reader.read_line(&mut line) {
+        Ok(0) => {
+            eprintln!("World Monitor closed the connection without responding");
+            std::process::exit(1);
+        }
+        Ok(_) => println!("{}", line.trim_end()),
+        Err(err) => {
+            eprintln!("Failed to read response: {err}");
+            std::process::exit(1);
+        }
+    }
+}